@@ -25,35 +25,134 @@
 //! Upon every call to [`make_hand`](Dispenser::make_hand), and upon [creation](Dispenser::new),
 //! the dispenser shuffles it's internal state, so that it becomes an internal state it may
 //! "dispense", and then change, which stays that way until the next "dispensing".
-use rand::prelude::ThreadRng;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
+pub use rand::distributions::WeightedError;
+use rand::distributions::WeightedIndex;
+use rand::prelude::{Distribution, StdRng, ThreadRng};
+use rand::{thread_rng, Rng, SeedableRng};
 
 /// A struct that holds a hidden variable, dispenses [`Hand`s](Hand) with a lock on a
 /// state, and shuffles afterward.
+///
+/// `Dispenser` is generic over the [`Rng`](Rng) that drives its shuffling, so that, where
+/// [`new`](Dispenser::new) reaches for [`ThreadRng`](ThreadRng) for convenience,
+/// [`from_rng`](Dispenser::from_rng) and [`from_seed`](Dispenser::from_seed) let a caller plug in
+/// a [`SeedableRng`](SeedableRng) instead, making the sequence of dispensed hands reproducible.
 #[derive(Debug)]
-pub struct Dispenser {
+pub struct Dispenser<R: Rng = ThreadRng> {
     seq: Vec<usize>,
-    rng: ThreadRng,
+    rng: R,
+    weights: Option<Vec<u32>>,
 }
 
-impl Dispenser {
+impl Dispenser<ThreadRng> {
     /// Creates a new [`Dispenser`](Dispenser), initializing it with choices for a slice of a given
-    /// `len`.
+    /// `len`, using [`thread_rng`](thread_rng) to shuffle.
     pub fn new(len: usize) -> Self {
+        Self::from_rng(len, thread_rng())
+    }
+
+    /// Creates a new weighted [`Dispenser`](Dispenser), using [`thread_rng`](thread_rng) to
+    /// shuffle, where `weights[i]` is the relative likelihood of choice `i` landing at a low
+    /// index of a dispensed [`Hand`](Hand).
+    ///
+    /// Returns a [`WeightedError`](WeightedError) under the same conditions as
+    /// [`WeightedIndex::new`](WeightedIndex::new): an empty `weights`, a weight that can't be
+    /// compared or summed, or all weights being zero.
+    pub fn new_weighted(weights: &[u32]) -> Result<Self, WeightedError> {
+        Self::from_rng_weighted(weights, thread_rng())
+    }
+
+    /// Creates a new [`Dispenser`](Dispenser) sized for `iter`, using [`thread_rng`](thread_rng)
+    /// to shuffle.
+    ///
+    /// `iter` is consumed to count its items and establish `len`, rather than requiring a caller
+    /// to know `len` up front; pair this with [`make_hand_from_iter`](Dispenser::make_hand_from_iter)
+    /// to later deal a [`Hand`](Hand) from another iterator of the same length.
+    #[allow(clippy::should_implement_trait)] // intentionally mirrors IteratorRandom-style naming
+    pub fn from_iter<I: IntoIterator>(iter: I) -> Self {
+        Self::from_rng_iter(iter, thread_rng())
+    }
+}
+
+impl Dispenser<StdRng> {
+    /// Creates a new [`Dispenser`](Dispenser) whose shuffling is driven by a [`StdRng`](StdRng)
+    /// seeded from `seed`, via [`SeedableRng::seed_from_u64`](SeedableRng::seed_from_u64).
+    ///
+    /// Two dispensers created from the same `len` and `seed` dispense the identical sequence of
+    /// hands, which makes this constructor suited for testing, replaying a game, or auditing a
+    /// hidden variable.
+    pub fn from_seed(len: usize, seed: u64) -> Self {
+        Self::from_rng(len, StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> Dispenser<R> {
+    /// Creates a new [`Dispenser`](Dispenser), initializing it with choices for a slice of a
+    /// given `len`, shuffled by the given `rng`.
+    pub fn from_rng(len: usize, rng: R) -> Self {
         let mut disp = Self {
             seq: (0..len).collect(),
-            rng: thread_rng(),
+            rng,
+            weights: None,
         };
         disp.shuffle();
         disp
     }
 
-    /// Returns the effective `len` argument given to [`new`](Dispenser::new) for this object.
+    /// Creates a new weighted [`Dispenser`](Dispenser), shuffled by the given `rng`, where
+    /// `weights[i]` is the relative likelihood of choice `i` landing at a low index of a
+    /// dispensed [`Hand`](Hand).
+    ///
+    /// Returns a [`WeightedError`](WeightedError) under the same conditions as
+    /// [`WeightedIndex::new`](WeightedIndex::new): an empty `weights`, a weight that can't be
+    /// compared or summed, or all weights being zero.
+    pub fn from_rng_weighted(weights: &[u32], rng: R) -> Result<Self, WeightedError> {
+        // Validate the weights up front, mirroring WeightedIndex's own error cases, so that a
+        // bad `weights` slice is rejected here rather than during the first shuffle.
+        WeightedIndex::new(weights)?;
+
+        let mut disp = Self {
+            seq: (0..weights.len()).collect(),
+            rng,
+            weights: Some(weights.to_vec()),
+        };
+        disp.shuffle();
+        Ok(disp)
+    }
+
+    /// Creates a new [`Dispenser`](Dispenser) sized for `iter`, shuffled by the given `rng`.
+    ///
+    /// `iter` is consumed to count its items and establish `len`, rather than requiring a caller
+    /// to know `len` up front.
+    pub fn from_rng_iter<I: IntoIterator>(iter: I, rng: R) -> Self {
+        Self::from_rng(iter.into_iter().count(), rng)
+    }
+
+    /// Returns the number of possible choices this dispenser was created with.
     pub fn len(&self) -> usize {
         self.seq.len()
     }
 
+    /// Returns a reference to the internal [`Rng`](Rng), so that its state can be inspected.
+    pub fn rng(&self) -> &R {
+        &self.rng
+    }
+
+    /// Consumes the [`Dispenser`](Dispenser), returning its internal [`Rng`](Rng) in its current
+    /// state.
+    ///
+    /// The returned `Rng` does not carry the dispenser's pending order: passing it to
+    /// [`from_rng`](Dispenser::from_rng) shuffles a fresh `0..len` order with it, so the new
+    /// dispenser's hands are not the ones this dispenser would have dealt next.
+    ///
+    /// Only an `R` with real snapshot semantics, such as a [`SeedableRng`](SeedableRng) like
+    /// [`StdRng`](StdRng), replays the same random draws when persisted and reused.
+    /// [`ThreadRng`](ThreadRng) has no such guarantee: it draws from an advancing thread-local
+    /// generator.
+    pub fn into_rng(self) -> R {
+        self.rng
+    }
+
     /// Creates a [`Hand`](Hand) from `deck` and an internal variable, this shuffles the variable afterwards.
     ///
     /// This makes first sure that all possible choices are possible for `deck`, it does this by
@@ -86,21 +185,175 @@ impl Dispenser {
         Hand::new(b, deck)
     }
 
+    /// Creates a [`Hand`](Hand) by draining `iter`, rather than borrowing elements from a `deck`
+    /// slice, pairing the frozen order with its own owned copy of the drained elements, via
+    /// [`Hand::from_iter`](Hand::from_iter).
+    ///
+    /// This makes first sure that `iter` yields exactly `len` elements, mirroring
+    /// [`make_hand`](Dispenser::make_hand)'s length check; it returns [`None`](None) if it
+    /// doesn't.
+    pub fn make_hand_from_iter<T, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Option<Hand<'static, T>> {
+        let elements: Vec<T> = iter.into_iter().collect();
+        if elements.len() != self.len() {
+            return None;
+        }
+
+        let b: Box<[usize]> = self.seq.clone().into_boxed_slice();
+        self.shuffle();
+        Some(Hand::from_iter(b, elements))
+    }
+
+    /// Creates a [`Hand`](Hand) that exposes only `k` of the `len` possible choices, rather than
+    /// a full permutation, e.g. for dealing a 5-card hand from a 52-card `deck` without
+    /// materializing the whole order.
+    ///
+    /// For `k` smaller than `len`, this picks `k` distinct indices, so [`choose`](Hand::choose)
+    /// ranges over `0..k` rather than `0..len`: via [Floyd's sampling
+    /// algorithm](Self::floyd_sample) followed by a uniform shuffle for an unweighted
+    /// [`Dispenser`](Dispenser), or via the same weighted sampling without replacement that
+    /// [`shuffle`](Self::shuffle) uses, stopped after `k` draws, for one built with
+    /// [`weights`](Dispenser::new_weighted) — so a weighted `Dispenser`'s bias still shows up in
+    /// partial hands. For `k == len` this falls back to the same full-permutation path as
+    /// [`make_hand_unchecked`](Dispenser::make_hand_unchecked).
+    ///
+    /// Like [`make_hand`](Dispenser::make_hand), this first checks that `deck.len()` matches
+    /// `len`, and returns [`None`](None) if `k` exceeds `len` as well.
+    pub fn make_partial_hand<'h, T>(&mut self, deck: &'h [T], k: usize) -> Option<Hand<'h, T>> {
+        if deck.len() != self.len() || k > self.len() {
+            return None;
+        }
+
+        let choices: Box<[usize]> = if k == self.len() {
+            self.seq.clone().into_boxed_slice()
+        } else {
+            match &self.weights {
+                Some(weights) => Self::weighted_order(weights, k, &mut self.rng).into_boxed_slice(),
+                None => {
+                    let mut picked = self.floyd_sample(k);
+                    Self::uniform_shuffle(&mut picked, &mut self.rng);
+                    picked.into_boxed_slice()
+                }
+            }
+        };
+        self.shuffle();
+        Some(Hand::new(choices, deck))
+    }
+
+    /// Picks `k` distinct indices from `0..self.len()` via Floyd's sampling algorithm: for `j` in
+    /// `len - k..len`, draws `t` uniformly from `0..=j`, and keeps `t` unless it was already
+    /// picked, in which case `j` is kept instead. This yields exactly `k` distinct indices in
+    /// `O(k)` space, without shuffling the full `len`-sized range.
+    fn floyd_sample(&mut self, k: usize) -> Vec<usize> {
+        let len = self.len();
+        let mut picked = std::collections::HashSet::with_capacity(k);
+        let mut result = Vec::with_capacity(k);
+
+        for j in (len - k)..len {
+            let t = self.rng.gen_range(0..=j as u32) as usize;
+            let idx = if picked.contains(&t) { j } else { t };
+            picked.insert(idx);
+            result.push(idx);
+        }
+
+        result
+    }
+
+    /// Re-derives `seq`'s order, either via an unweighted Fisher-Yates shuffle, or, if this
+    /// [`Dispenser`](Dispenser) was built with [`weights`](Dispenser::new_weighted), via repeated
+    /// weighted sampling without replacement.
     fn shuffle(&mut self) {
-        self.seq.shuffle(&mut self.rng);
+        match &self.weights {
+            Some(weights) => self.seq = Self::weighted_order(weights, weights.len(), &mut self.rng),
+            None => Self::uniform_shuffle(&mut self.seq, &mut self.rng),
+        }
+    }
+
+    /// Shuffles `seq` via Fisher-Yates, sampling swap indices as `u32` rather than `usize` so
+    /// that, given the same seed, the resulting order is identical on 32- and 64-bit targets.
+    fn uniform_shuffle(seq: &mut [usize], rng: &mut R) {
+        let len = seq.len();
+        for i in (1..len).rev() {
+            let j = rng.gen_range(0..=i as u32) as usize;
+            seq.swap(i, j);
+        }
+    }
+
+    /// Builds an order over the first `k` picks of `0..weights.len()` by repeatedly drawing a
+    /// [`WeightedIndex`](WeightedIndex) over the remaining items, appending the draw, and
+    /// removing its weight, `k` times. Items that only have zero weight left among the remaining
+    /// choices are drawn in their original relative order.
+    fn weighted_order(weights: &[u32], k: usize, rng: &mut R) -> Vec<usize> {
+        let mut remaining: Vec<usize> = (0..weights.len()).collect();
+        let mut order = Vec::with_capacity(k);
+
+        for _ in 0..k {
+            let picked = match WeightedIndex::new(remaining.iter().map(|&i| weights[i])) {
+                Ok(dist) => dist.sample(rng),
+                // All remaining weights are zero: WeightedIndex::new refuses this, so fall back
+                // to draining the remainder in order.
+                Err(_) => 0,
+            };
+            order.push(remaining.remove(picked));
+        }
+
+        order
+    }
+}
+
+impl<R: Rng + Clone> Dispenser<R> {
+    /// Clones the internal [`Rng`](Rng)'s current state, without consuming this
+    /// [`Dispenser`](Dispenser).
+    ///
+    /// As with [`into_rng`](Dispenser::into_rng), the clone does not carry the dispenser's pending
+    /// order, so a dispenser built from it via [`from_rng`](Dispenser::from_rng) does not deal the
+    /// hands this one would deal next. Only an `R` with real snapshot semantics, such as a
+    /// [`SeedableRng`](SeedableRng) like [`StdRng`](StdRng), replays the same random draws from
+    /// the clone; cloning a [`ThreadRng`](ThreadRng) does not snapshot it, since both clones keep
+    /// drawing from the same advancing thread-local generator.
+    pub fn clone_rng(&self) -> R {
+        self.rng.clone()
+    }
+}
+
+/// The elements half of a [`Hand`](Hand): either borrowed from a caller-owned `deck`, or, when
+/// dealt via [`Hand::from_iter`](Hand::from_iter), drained from an iterator and owned outright.
+#[derive(Debug)]
+enum Elements<'h, T> {
+    Borrowed(&'h [T]),
+    Owned(Box<[T]>),
+}
+
+impl<'h, T> Elements<'h, T> {
+    fn as_slice(&self) -> &[T] {
+        match self {
+            Elements::Borrowed(s) => s,
+            Elements::Owned(s) => s,
+        }
     }
 }
 
 /// A lock on a slice of choices, with a slice of elements to match them.
 #[derive(Debug)]
-pub struct Hand<'h, T>(Box<[usize]>, &'h [T]);
+pub struct Hand<'h, T>(Box<[usize]>, Elements<'h, T>);
 
 impl<'h, T> Hand<'h, T> {
     /// Creates a new hand from a slice of choices, and a slice of elements.
     ///
     /// Slice length equivalence isn't checked.
     pub fn new(choices: Box<[usize]>, elements: &'h [T]) -> Hand<'h, T> {
-        Hand(choices, elements)
+        Hand(choices, Elements::Borrowed(elements))
+    }
+
+    /// Creates a new hand from a slice of choices and elements drained from `iter`, pairing the
+    /// frozen `choices` order with its own, owned copy of the elements, rather than borrowing
+    /// them from a caller-owned `deck`.
+    ///
+    /// Slice length equivalence isn't checked.
+    pub fn from_iter<I: IntoIterator<Item = T>>(choices: Box<[usize]>, iter: I) -> Hand<'h, T> {
+        Hand(choices, Elements::Owned(iter.into_iter().collect()))
     }
 
     /// Pick from a series of choices by index, which then picks a corresponding element from the list.
@@ -132,13 +385,25 @@ impl<'h, T> Hand<'h, T> {
     /// Returns [`Some`](Some) with a reference to an element if the choosing succeeds.
     pub fn choose(&self, idx: usize) -> Option<&T> {
         if let Some(u) = self.0.get(idx) {
-            if let Some(t) = self.1.get(u.to_owned()) {
+            if let Some(t) = self.1.as_slice().get(u.to_owned()) {
                 return Some(t);
             }
         }
         None
     }
 
+    /// Picks one element uniformly at random via the hand's own frozen order, mirroring
+    /// [`SliceRandom::choose`](rand::seq::SliceRandom::choose).
+    ///
+    /// Returns [`None`](None) if this hand has no choices.
+    pub fn choose_random<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if self.len() == 0 {
+            None
+        } else {
+            self.choose(rng.gen_range(0..self.len()))
+        }
+    }
+
     /// Returns the amount of choices that this hand has.
     pub fn len(&self) -> usize {
         self.0.len()
@@ -202,3 +467,127 @@ fn some_and_none() {
     assert!(za_hando.choose(0).is_some());
     assert!(za_hando.choose(1).is_none());
 }
+
+#[test]
+fn weighted() {
+    let choices = vec!['a', 'b', 'c'];
+    let mut dispenser =
+        Dispenser::new_weighted(&[100, 0, 0]).expect("weights are non-empty and not all zero");
+    let hand = dispenser
+        .make_hand(&choices)
+        .expect("same length as weights");
+
+    // Only index 0 has nonzero weight, so it deterministically lands first, regardless of rng
+    // state: the other two indices have zero probability of being drawn before it.
+    assert_eq!(hand.choose(0), Some(&'a'));
+}
+
+#[test]
+fn partial_hand() {
+    let deck = (0..52u8).collect::<Vec<u8>>();
+    let mut dispenser = Dispenser::new(deck.len());
+    let hand = dispenser
+        .make_partial_hand(&deck, 5)
+        .expect("same length as deck, k within len");
+
+    assert_eq!(hand.len(), 5);
+
+    let mut drawn: Vec<u8> = (0..hand.len())
+        .map(|idx| *hand.choose(idx).unwrap())
+        .collect();
+    drawn.sort_unstable();
+    drawn.dedup();
+    assert_eq!(drawn.len(), 5, "all 5 drawn cards should be distinct");
+
+    assert!(hand.choose(5).is_none());
+}
+
+#[test]
+fn partial_hand_full_k() {
+    let deck = vec!['a', 'b', 'c'];
+    let mut dispenser = Dispenser::new(deck.len());
+    let hand = dispenser
+        .make_partial_hand(&deck, 3)
+        .expect("k == len falls back to a full permutation");
+
+    assert_eq!(hand.len(), 3);
+}
+
+#[test]
+fn partial_hand_bad_k() {
+    let deck = vec!['a', 'b', 'c'];
+    let mut dispenser = Dispenser::new(deck.len());
+    assert!(dispenser.make_partial_hand(&deck, 4).is_none());
+}
+
+#[test]
+fn partial_hand_respects_weights() {
+    let deck = vec!['a', 'b', 'c'];
+    let mut dispenser =
+        Dispenser::new_weighted(&[100, 0, 0]).expect("weights are non-empty and not all zero");
+    let hand = dispenser
+        .make_partial_hand(&deck, 1)
+        .expect("same length as weights, k within len");
+
+    // Only index 0 has nonzero weight, so it deterministically wins the single partial draw,
+    // regardless of rng state.
+    assert_eq!(hand.choose(0), Some(&'a'));
+}
+
+#[test]
+fn weighted_errors() {
+    assert!(matches!(
+        Dispenser::new_weighted(&[]),
+        Err(WeightedError::NoItem)
+    ));
+    assert!(matches!(
+        Dispenser::new_weighted(&[0, 0, 0]),
+        Err(WeightedError::AllWeightsZero)
+    ));
+}
+
+#[test]
+fn from_iter() {
+    let choices = ['a', 'b', 'c', 'd'];
+    let mut dispenser = Dispenser::from_iter(choices);
+
+    assert_eq!(dispenser.len(), choices.len());
+
+    let hand = dispenser
+        .make_hand(&choices)
+        .expect("same length as choices");
+    assert!(choices.contains(hand.choose(0).unwrap()));
+}
+
+#[test]
+fn make_hand_from_iter() {
+    // Sized for an iterator of unknown length up front, then dealt from a second iterator of
+    // elements, as documented on `Dispenser::from_iter`.
+    let mut dispenser = Dispenser::from_iter(std::iter::repeat_n((), 4));
+
+    let hand = dispenser
+        .make_hand_from_iter(['a', 'b', 'c', 'd'])
+        .expect("iterator yields exactly `len` elements");
+    assert!(['a', 'b', 'c', 'd'].contains(hand.choose(0).unwrap()));
+
+    assert!(dispenser.make_hand_from_iter(['a', 'b']).is_none());
+}
+
+#[test]
+fn hand_from_iter() {
+    let dispenser_choices: Box<[usize]> = Box::from([2, 0, 1]);
+    let hand = Hand::from_iter(dispenser_choices, vec!["a", "b", "c"]);
+
+    assert_eq!(hand.len(), 3);
+    assert_eq!(hand.choose(0).unwrap(), &"c"); // idx 0 -> choice 2 -> element c
+    assert_eq!(hand.choose(1).unwrap(), &"a"); // idx 1 -> choice 0 -> element a
+}
+
+#[test]
+fn choose_random() {
+    let choices = vec![0];
+    let mut dispenser = Dispenser::new(choices.len());
+    let hand = dispenser.make_hand_unchecked(&choices);
+
+    assert_eq!(hand.choose_random(&mut thread_rng()), Some(&0));
+}
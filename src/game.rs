@@ -0,0 +1,181 @@
+//! A simultaneous-move game environment built on [`Hand`](crate::dispenser::Hand) as each
+//! player's private, hidden commitment.
+//!
+//! This generalizes a rock-paper-scissors-style simultaneous choice: each player holds a
+//! [`Hand`](crate::dispenser::Hand) dealt from a shared [`Dispenser`](crate::dispenser::Dispenser),
+//! so neither can see the other's realized index→element mapping, while their own mapping stays
+//! stable within a round. [`Game`](Game) resolves both players' picks against a user-supplied
+//! payoff function, mirroring the step/reset interface common to reinforcement-learning
+//! environments via the [`Environment`](Environment) trait.
+//!
+//! ```
+//! use hidden::dispenser::Dispenser;
+//! use hidden::game::{Environment, Game};
+//!
+//! // 'C'ooperate or 'D'efect, hidden behind a shuffled mapping per player.
+//! let moves = ['C', 'D'];
+//! let mut dispenser = Dispenser::new(moves.len());
+//!
+//! let mut game = Game::new(&mut dispenser, &moves, |a, b| match (a, b) {
+//!     ('C', 'C') => (3.0, 3.0),
+//!     ('C', 'D') => (0.0, 5.0),
+//!     ('D', 'C') => (5.0, 0.0),
+//!     ('D', 'D') => (1.0, 1.0),
+//!     _ => unreachable!(),
+//! })
+//! .expect("moves.len() matches the dispenser's len");
+//!
+//! let (_, reward, done) = game.step((0, 0));
+//! assert!(done);
+//! // Index 0 maps independently for each player, so the outcome could be any of the 4 cells.
+//! assert!([(3.0, 3.0), (0.0, 5.0), (5.0, 0.0), (1.0, 1.0)].contains(&reward));
+//! ```
+use crate::dispenser::{Dispenser, Hand};
+use rand::prelude::ThreadRng;
+use rand::Rng;
+
+/// An `Environment`-like trait for driving agents or tournaments over a hidden-choice mechanic,
+/// mirroring the step/reset interface common to reinforcement-learning environments.
+pub trait Environment {
+    /// What [`reset`](Environment::reset) and [`step`](Environment::step) hand back to the caller
+    /// to observe the current round.
+    type Observation;
+    /// What a caller passes to [`step`](Environment::step) to act in the current round.
+    type Action;
+    /// The per-player reward [`step`](Environment::step) resolves an action into.
+    type Reward;
+
+    /// Deals a fresh round, returning its initial observation.
+    fn reset(&mut self) -> Self::Observation;
+
+    /// Resolves `action` against the current round, returning the resulting observation, reward,
+    /// and whether the round is done.
+    fn step(&mut self, action: Self::Action) -> (Self::Observation, Self::Reward, bool);
+}
+
+/// A two-player, simultaneous-move game built on [`Hand`](Hand) as each player's private, frozen
+/// choice space.
+///
+/// Both players' [`Hand`s](Hand) are dealt from the same [`Dispenser`](Dispenser), shuffled
+/// independently of each other, so neither player can see the other's realized choice mapping;
+/// [`step`](Environment::step) resolves both players' [`choose`](Hand::choose) outputs through a
+/// user-supplied `payoff` function.
+pub struct Game<'d, T, F, R = ThreadRng>
+where
+    R: Rng,
+    F: Fn(&T, &T) -> (f64, f64),
+{
+    dispenser: &'d mut Dispenser<R>,
+    deck: &'d [T],
+    payoff: F,
+    hand_a: Hand<'d, T>,
+    hand_b: Hand<'d, T>,
+    done: bool,
+}
+
+impl<'d, T, F, R> Game<'d, T, F, R>
+where
+    R: Rng,
+    F: Fn(&T, &T) -> (f64, f64),
+{
+    /// Creates a new [`Game`](Game) over `deck`, dealing each player's initial [`Hand`](Hand)
+    /// from `dispenser`, and resolving future actions through `payoff`.
+    ///
+    /// Returns [`None`](None) if `deck.len()` doesn't match `dispenser`'s `len`, mirroring
+    /// [`Dispenser::make_hand`](Dispenser::make_hand).
+    pub fn new(dispenser: &'d mut Dispenser<R>, deck: &'d [T], payoff: F) -> Option<Self> {
+        let hand_a = dispenser.make_hand(deck)?;
+        let hand_b = dispenser.make_hand(deck)?;
+
+        Some(Self {
+            dispenser,
+            deck,
+            payoff,
+            hand_a,
+            hand_b,
+            done: false,
+        })
+    }
+
+    /// Returns whether the current round has already been [`step`](Environment::step)ped.
+    pub fn done(&self) -> bool {
+        self.done
+    }
+}
+
+impl<'d, T, F, R> Environment for Game<'d, T, F, R>
+where
+    R: Rng,
+    F: Fn(&T, &T) -> (f64, f64),
+{
+    /// A round has no observable state ahead of [`step`](Environment::step): both hands are
+    /// hidden by design.
+    type Observation = ();
+    /// Both players' chosen indices into their own [`Hand`](Hand), `(action_a, action_b)`.
+    type Action = (usize, usize);
+    /// Per-player reward, `(reward_a, reward_b)`, as resolved by the payoff function.
+    type Reward = (f64, f64);
+
+    fn reset(&mut self) -> Self::Observation {
+        self.hand_a = self.dispenser.make_hand_unchecked(self.deck);
+        self.hand_b = self.dispenser.make_hand_unchecked(self.deck);
+        self.done = false;
+    }
+
+    fn step(
+        &mut self,
+        (action_a, action_b): Self::Action,
+    ) -> (Self::Observation, Self::Reward, bool) {
+        self.done = true;
+
+        let reward = match (self.hand_a.choose(action_a), self.hand_b.choose(action_b)) {
+            (Some(a), Some(b)) => (self.payoff)(a, b),
+            // An out-of-range action resolves to no reward for either player, rather than
+            // panicking, mirroring Hand::choose's own Option-based fallibility.
+            _ => (0.0, 0.0),
+        };
+
+        ((), reward, self.done)
+    }
+}
+
+// Tests
+
+#[test]
+fn rock_paper_scissors() {
+    let moves = ['R', 'P', 'S'];
+    let mut dispenser = Dispenser::new(moves.len());
+
+    let payoff = |a: &char, b: &char| -> (f64, f64) {
+        match (a, b) {
+            (a, b) if a == b => (0.0, 0.0),
+            ('R', 'S') | ('S', 'P') | ('P', 'R') => (1.0, -1.0),
+            _ => (-1.0, 1.0),
+        }
+    };
+
+    let mut game = Game::new(&mut dispenser, &moves, payoff).expect("same length as moves");
+    assert!(!game.done());
+
+    let (obs, reward, done) = game.step((0, 0));
+    assert_eq!(obs, ());
+    assert!(done);
+    assert!(game.done());
+    // Both hands were shuffled independently, so index 0 can resolve to any payoff cell.
+    assert!(reward == (0.0, 0.0) || reward == (1.0, -1.0) || reward == (-1.0, 1.0));
+
+    game.reset();
+    assert!(!game.done());
+}
+
+#[test]
+fn out_of_range_action_is_scoreless() {
+    let moves = ['R', 'P', 'S'];
+    let mut dispenser = Dispenser::new(moves.len());
+    let mut game =
+        Game::new(&mut dispenser, &moves, |_, _| (1.0, 1.0)).expect("same length as moves");
+
+    let (_, reward, done) = game.step((0, moves.len()));
+    assert!(done);
+    assert_eq!(reward, (0.0, 0.0));
+}
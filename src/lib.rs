@@ -0,0 +1,8 @@
+//! `hidden` provides a small, hidden-variable primitive for dealing reproducible, masked choices.
+//!
+//! See the [`dispenser`](dispenser) module for the core [`Dispenser`](dispenser::Dispenser) and
+//! [`Hand`](dispenser::Hand) types, and the [`game`](game) module for a simultaneous-move game
+//! environment built on top of them.
+
+pub mod dispenser;
+pub mod game;